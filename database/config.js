@@ -0,0 +1,46 @@
+// Database settings, overridable from the environment.
+
+// setTimeout treats anything above this as 1 ms.
+const MAX_TIMEOUT = 2147483647;
+
+function intFromEnv(name, fallback) {
+    const value = process.env[name];
+    if (value === undefined || value === '') { return fallback; }
+    return /^-?\d+$/.test(value.trim()) ? parseInt(value, 10) : NaN;
+}
+
+function fail(message) {
+    console.log(message);
+    process.exit(1);
+}
+
+// Pool sizing and statement timeout.
+const pool = {
+    maxConnections: intFromEnv('DB_MAX_CONNECTIONS', 5),
+    minConnections: intFromEnv('DB_MIN_CONNECTIONS', 0),
+    acquireTimeout: intFromEnv('DB_ACQUIRE_TIMEOUT', 60000),
+    statementTimeout: intFromEnv('DB_STATEMENT_TIMEOUT', 0)
+};
+
+if ( !(pool.maxConnections >= 1) || !(pool.minConnections >= 0) || pool.minConnections > pool.maxConnections
+     || !(pool.acquireTimeout >= 0) || !(pool.statementTimeout >= 0) ) {
+    fail('Invalid database pool settings: values must be integers, DB_MAX_CONNECTIONS must be at least 1, '
+       + 'DB_MIN_CONNECTIONS must be between 0 and DB_MAX_CONNECTIONS, '
+       + 'and DB_ACQUIRE_TIMEOUT and DB_STATEMENT_TIMEOUT must not be negative.');
+};
+
+// Startup retry, so the server can wait for a database that is still booting.
+const connect = {
+    retries: intFromEnv('DB_CONNECT_RETRIES', 5),
+    retryDelay: intFromEnv('DB_CONNECT_RETRY_DELAY', 1000),
+    retryMaxDelay: intFromEnv('DB_CONNECT_RETRY_MAX_DELAY', 30000)
+};
+
+if ( !(connect.retries >= 1)
+     || !(connect.retryDelay >= 0 && connect.retryDelay <= MAX_TIMEOUT)
+     || !(connect.retryMaxDelay >= 0 && connect.retryMaxDelay <= MAX_TIMEOUT) ) {
+    fail('Invalid database retry settings: values must be integers, DB_CONNECT_RETRIES must be at least 1, '
+       + `and DB_CONNECT_RETRY_DELAY and DB_CONNECT_RETRY_MAX_DELAY must be between 0 and ${MAX_TIMEOUT}.`);
+};
+
+module.exports = { intFromEnv, pool, connect };
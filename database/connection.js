@@ -1,4 +1,5 @@
 const fs = require('fs');
+const { pool } = require('./config');
 
 const rawdata = fs.readFileSync('credentials.json');
 const credentials = JSON.parse(rawdata);
@@ -14,5 +15,19 @@ if ( !host || !username || !password  || !dbName ) {
 
 const { Sequelize } = require('sequelize');
 const connectionString = `mariadb://${username}:${password}@${host}:3306/${dbName}`;
-const sequelize = new Sequelize(connectionString);
-module.exports = sequelize;
\ No newline at end of file
+const sequelize = new Sequelize(connectionString, {
+    pool: {
+        max: pool.maxConnections,
+        min: pool.minConnections,
+        acquire: pool.acquireTimeout
+    },
+    hooks: {
+        // MariaDB's max_statement_time is in seconds; 0 leaves it unbounded.
+        afterConnect: async (connection) => {
+            if (pool.statementTimeout > 0) {
+                await connection.query(`SET SESSION max_statement_time = ${pool.statementTimeout / 1000}`);
+            }
+        }
+    }
+});
+module.exports = sequelize;
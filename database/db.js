@@ -1,16 +1,29 @@
 const { Sequelize, DataTypes } = require('sequelize');
 const sequelize = require('./connection');
+const { connect: retry } = require('./config');
 
 // Table definitions and validators.
 // https://sequelize.org/v6/manual/models-definition.html#defining-as-part-of-a-property
 
 
 
-sequelize.authenticate().then(res => {
-    console.log('Connection has been established successfully.');
-}).catch(err => {
-    console.log(`${err.message}`)
-})
+// Startup retry, so the server can wait for a database that is still booting.
+const sleep = (ms) => new Promise(resolve => setTimeout(resolve, ms));
+
+async function connect() {
+    for (let attempt = 1; attempt <= retry.retries; attempt++) {
+        try {
+            await sequelize.authenticate();
+            console.log('Connection has been established successfully.');
+            return;
+        } catch (err) {
+            console.log(`Connection attempt ${attempt} of ${retry.retries} failed: ${err.message}`);
+            if (attempt === retry.retries) { throw err; }
+            // Back off exponentially between attempts, capped at the max delay.
+            await sleep(Math.min(retry.retryDelay * 2 ** (attempt - 1), retry.retryMaxDelay));
+        }
+    }
+}
 
 const Users = sequelize.define('users', {
     // Model attributes are defined here
@@ -37,8 +50,16 @@ const Users = sequelize.define('users', {
     underscored: true
 });
 
-sequelize.sync({ force: true }).then(() => {
-    console.log("Drop and re-sync db.");
+connect().then(() => {
+    return sequelize.sync({ force: true }).then(() => {
+        console.log("Drop and re-sync db.");
+    }).catch(err => {
+        console.log(`Unable to sync the database schema: ${err.message}`);
+        process.exit(1);
+    });
+}, err => {
+    console.log(`Unable to connect to the database, giving up: ${err.message}`);
+    process.exit(1);
 });
 
 module.exports = { Users }
\ No newline at end of file